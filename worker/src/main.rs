@@ -2,13 +2,19 @@ use std::collections::BTreeMap;
 use std::fs;
 use std::io::Write;
 use std::path::{Path, PathBuf};
+use std::sync::atomic::{AtomicBool, AtomicU64, Ordering};
+use std::sync::Mutex;
 use std::thread;
 use std::time::Duration;
 
 use base64::Engine;
-use clap::{Parser, Subcommand};
+use clap::{Parser, Subcommand, ValueEnum};
 use ed25519_dalek::{Signature, Signer, SigningKey, Verifier, VerifyingKey};
+use p256::ecdsa::{
+    Signature as P256Signature, SigningKey as P256SigningKey, VerifyingKey as P256VerifyingKey,
+};
 use rand::rngs::OsRng;
+use rand::RngCore;
 use serde::{Deserialize, Serialize};
 use serde_json::{Map, Value};
 use sha2::{Digest, Sha256};
@@ -33,9 +39,68 @@ enum Commands {
         #[arg(long)]
         region: String,
     },
-    Keys,
+    Keys {
+        /// Deterministically derive the signing key from a memorable passphrase
+        /// instead of generating a random one. Running this again with the same
+        /// phrase recovers the identical key.
+        #[arg(long)]
+        from_phrase: Option<String>,
+        /// Keep generating keys until the base64 verifying key starts with this
+        /// prefix. Mutually exclusive with `--from-phrase`.
+        #[arg(long)]
+        prefix: Option<String>,
+        /// Give up the vanity search after this many total attempts.
+        #[arg(long)]
+        max_tries: Option<u64>,
+        /// Signature algorithm to generate. `--from-phrase`/`--prefix` only
+        /// apply to the default `ed25519`.
+        #[arg(long, value_enum, default_value_t = KeyType::Ed25519)]
+        alg: KeyType,
+    },
     Bench,
     Start,
+    /// Sign a JSON file with the worker's stored key and print the signature.
+    Sign {
+        #[arg(long)]
+        file: PathBuf,
+    },
+    /// Verify a base64 signature over a JSON file against a base64 verifying key.
+    Verify {
+        #[arg(long)]
+        file: PathBuf,
+        #[arg(long)]
+        signature: String,
+        #[arg(long)]
+        public_key: String,
+        #[arg(long, value_enum, default_value_t = KeyType::Ed25519)]
+        alg: KeyType,
+    },
+    /// Verify a base64 signature over a JSON file against this worker's own public key.
+    Recover {
+        #[arg(long)]
+        file: PathBuf,
+        #[arg(long)]
+        signature: String,
+    },
+}
+
+/// Identifies which signature backend a worker identity (or a coordinator
+/// request) uses, the way a JWS `alg` header picks a verification algorithm.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize, ValueEnum)]
+#[serde(rename_all = "kebab-case")]
+enum KeyType {
+    #[default]
+    Ed25519,
+    EcdsaP256,
+}
+
+impl KeyType {
+    fn jws_alg(self) -> &'static str {
+        match self {
+            KeyType::Ed25519 => "EdDSA",
+            KeyType::EcdsaP256 => "ES256",
+        }
+    }
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -45,6 +110,10 @@ struct Config {
     name: String,
     region: String,
     public_key: Option<String>,
+    #[serde(default)]
+    phrase_derived: bool,
+    #[serde(default)]
+    key_type: KeyType,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -55,6 +124,70 @@ struct BenchSpec {
     gpu: Option<String>,
 }
 
+/// RFC 7515 flattened JWS protected header. `alg` is carried alongside the
+/// signature so a coordinator can select the matching verification backend
+/// instead of assuming ed25519.
+#[derive(Debug, Serialize)]
+struct JwsProtectedHeader<'a> {
+    alg: &'a str,
+    kid: &'a str,
+    url: &'a str,
+}
+
+/// A worker identity capable of signing and self-verifying, independent of
+/// the underlying curve. Which backend is used is selected at runtime from
+/// the `KeyType` recorded in `Config` when the key was generated; the worker
+/// does not exchange anything with the coordinator to pick an algorithm.
+trait KeyBackend {
+    fn key_type(&self) -> KeyType;
+    fn sign(&self, message: &[u8]) -> Vec<u8>;
+    fn verify(&self, message: &[u8], signature_b64: &str) -> Result<bool, String>;
+    fn to_public_b64(&self) -> String;
+}
+
+struct Ed25519Backend(SigningKey);
+
+impl KeyBackend for Ed25519Backend {
+    fn key_type(&self) -> KeyType {
+        KeyType::Ed25519
+    }
+
+    fn sign(&self, message: &[u8]) -> Vec<u8> {
+        self.0.sign(message).to_bytes().to_vec()
+    }
+
+    fn verify(&self, message: &[u8], signature_b64: &str) -> Result<bool, String> {
+        verify_ed25519(&self.0.verifying_key(), message, signature_b64)
+    }
+
+    fn to_public_b64(&self) -> String {
+        base64::engine::general_purpose::STANDARD.encode(self.0.verifying_key().to_bytes())
+    }
+}
+
+struct EcdsaP256Backend(P256SigningKey);
+
+impl KeyBackend for EcdsaP256Backend {
+    fn key_type(&self) -> KeyType {
+        KeyType::EcdsaP256
+    }
+
+    fn sign(&self, message: &[u8]) -> Vec<u8> {
+        use p256::ecdsa::signature::Signer as _;
+        let signature: P256Signature = self.0.sign(message);
+        signature.to_bytes().to_vec()
+    }
+
+    fn verify(&self, message: &[u8], signature_b64: &str) -> Result<bool, String> {
+        verify_ecdsa_p256(self.0.verifying_key(), message, signature_b64)
+    }
+
+    fn to_public_b64(&self) -> String {
+        base64::engine::general_purpose::STANDARD
+            .encode(self.0.verifying_key().to_encoded_point(true).as_bytes())
+    }
+}
+
 fn main() -> Result<(), String> {
     init_tracing();
     let cli = Cli::parse();
@@ -71,10 +204,25 @@ fn main() -> Result<(), String> {
             name,
             region,
             public_key: None,
+            phrase_derived: false,
+            key_type: KeyType::default(),
         }),
-        Commands::Keys => cmd_keys(),
+        Commands::Keys {
+            from_phrase,
+            prefix,
+            max_tries,
+            alg,
+        } => cmd_keys(from_phrase.as_deref(), prefix.as_deref(), max_tries, alg),
         Commands::Bench => cmd_bench(),
         Commands::Start => cmd_start(),
+        Commands::Sign { file } => cmd_sign(&file),
+        Commands::Verify {
+            file,
+            signature,
+            public_key,
+            alg,
+        } => cmd_verify(&file, &signature, &public_key, alg),
+        Commands::Recover { file, signature } => cmd_recover(&file, &signature),
     }
 }
 
@@ -103,6 +251,22 @@ fn specs_path() -> Result<PathBuf, String> {
     Ok(openmesh_dir()?.join("specs.json"))
 }
 
+fn nonce_counter_path() -> Result<PathBuf, String> {
+    Ok(openmesh_dir()?.join("nonce_counter"))
+}
+
+fn recent_nonces_path() -> Result<PathBuf, String> {
+    Ok(openmesh_dir()?.join("recent_nonces"))
+}
+
+fn pending_nonce_path() -> Result<PathBuf, String> {
+    Ok(openmesh_dir()?.join("pending_nonce"))
+}
+
+fn pending_counter_path() -> Result<PathBuf, String> {
+    Ok(openmesh_dir()?.join("pending_counter"))
+}
+
 fn cmd_init(config: Config) -> Result<(), String> {
     let path = config_path()?;
     let toml = toml::to_string_pretty(&config).map_err(|e| format!("serialize config failed: {e}"))?;
@@ -111,19 +275,49 @@ fn cmd_init(config: Config) -> Result<(), String> {
     Ok(())
 }
 
-fn cmd_keys() -> Result<(), String> {
-    let mut rng = OsRng;
-    let signing_key = SigningKey::generate(&mut rng);
-    let verifying_key = signing_key.verifying_key();
+/// Number of iterated SHA-256 rounds used to stretch a passphrase into a key.
+const PHRASE_KDF_ROUNDS: u32 = 1 << 16;
+
+fn cmd_keys(
+    from_phrase: Option<&str>,
+    prefix: Option<&str>,
+    max_tries: Option<u64>,
+    alg: KeyType,
+) -> Result<(), String> {
+    if alg != KeyType::Ed25519 && (from_phrase.is_some() || prefix.is_some()) {
+        return Err("--from-phrase and --prefix are only supported for --alg ed25519".to_string());
+    }
+
+    let (private_bytes, identity): (Vec<u8>, Box<dyn KeyBackend>) = match alg {
+        KeyType::Ed25519 => {
+            let signing_key = match (from_phrase, prefix) {
+                (Some(phrase), None) => SigningKey::from_bytes(&derive_key_from_phrase(phrase)),
+                (None, Some(prefix)) => generate_vanity_key(prefix, max_tries)?,
+                (None, None) => SigningKey::generate(&mut OsRng),
+                (Some(_), Some(_)) => {
+                    return Err("--from-phrase and --prefix are mutually exclusive".to_string())
+                }
+            };
+            let private_bytes = signing_key.to_bytes().to_vec();
+            (private_bytes, Box::new(Ed25519Backend(signing_key)))
+        }
+        KeyType::EcdsaP256 => {
+            let signing_key = P256SigningKey::random(&mut OsRng);
+            let private_bytes = signing_key.to_bytes().to_vec();
+            (private_bytes, Box::new(EcdsaP256Backend(signing_key)))
+        }
+    };
 
-    let private_b64 = base64::engine::general_purpose::STANDARD.encode(signing_key.to_bytes());
-    let public_b64 = base64::engine::general_purpose::STANDARD.encode(verifying_key.to_bytes());
+    let private_b64 = base64::engine::general_purpose::STANDARD.encode(private_bytes);
+    let public_b64 = identity.to_public_b64();
 
     let path = key_path()?;
     write_private_key_secure(&path, &private_b64)?;
 
     let mut cfg = read_config_optional()?.ok_or_else(|| "run init before keys".to_string())?;
     cfg.public_key = Some(public_b64.clone());
+    cfg.phrase_derived = from_phrase.is_some();
+    cfg.key_type = alg;
     let cfg_path = config_path()?;
     let toml = toml::to_string_pretty(&cfg).map_err(|e| format!("serialize config failed: {e}"))?;
     fs::write(cfg_path, toml).map_err(|e| format!("update config failed: {e}"))?;
@@ -132,6 +326,106 @@ fn cmd_keys() -> Result<(), String> {
     Ok(())
 }
 
+/// Stretches a UTF-8 passphrase into 32 key bytes via iterated hashing:
+/// `h_0 = SHA256(phrase)`, `h_{i+1} = SHA256(h_i || phrase)`. The fixed round
+/// count makes brute-forcing the phrase from the derived key expensive while
+/// keeping derivation deterministic, so the same phrase always recovers the
+/// same signing key.
+fn derive_key_from_phrase(phrase: &str) -> [u8; 32] {
+    let mut digest = sha256_bytes(phrase.as_bytes());
+    for _ in 0..PHRASE_KDF_ROUNDS {
+        let mut input = Vec::with_capacity(digest.len() + phrase.len());
+        input.extend_from_slice(&digest);
+        input.extend_from_slice(phrase.as_bytes());
+        digest = sha256_bytes(&input);
+    }
+    digest
+}
+
+/// Characters that can appear in a `STANDARD`-engine base64 string, the
+/// encoding used for verifying keys. A `--prefix` containing anything else
+/// can never match and would otherwise keep every core busy until the whole
+/// `max_tries` budget is used up.
+const BASE64_ALPHABET: &[u8] = b"ABCDEFGHIJKLMNOPQRSTUVWXYZabcdefghijklmnopqrstuvwxyz0123456789+/=";
+
+/// A 32-byte ed25519 verifying key base64-encodes to exactly this many
+/// characters, so no `--prefix` longer than this can ever match.
+const VANITY_KEY_B64_LEN: usize = 44;
+
+/// Upper bound on total generation attempts when `--max-tries` is not given,
+/// so an infeasible `--prefix` (e.g. one that is merely unlikely rather than
+/// provably unreachable) still fails in bounded time instead of spinning
+/// every core forever.
+const DEFAULT_VANITY_MAX_TRIES: u64 = 50_000_000;
+
+/// Spawns one worker thread per available CPU, each generating random ed25519
+/// keypairs until one's base64 verifying key starts with `prefix`. Returns the
+/// first match found across all threads, or an error once `max_tries` total
+/// attempts have been made without a hit. `prefix` is checked up front for
+/// characters outside the base64 alphabet and for being longer than a
+/// verifying key, so those requests fail immediately; any other unreachable
+/// prefix (such as one with `=` before the last position) runs until
+/// `max_tries` is exhausted.
+fn generate_vanity_key(prefix: &str, max_tries: Option<u64>) -> Result<SigningKey, String> {
+    if prefix.is_empty() {
+        return Err("--prefix must not be empty".to_string());
+    }
+    if !prefix.bytes().all(|b| BASE64_ALPHABET.contains(&b)) {
+        return Err(format!(
+            "--prefix \"{prefix}\" contains characters outside the base64 alphabet and can never match"
+        ));
+    }
+    if prefix.len() > VANITY_KEY_B64_LEN {
+        return Err(format!(
+            "--prefix \"{prefix}\" is longer than a base64 verifying key ({VANITY_KEY_B64_LEN} chars) and can never match"
+        ));
+    }
+    let max_tries = Some(max_tries.unwrap_or(DEFAULT_VANITY_MAX_TRIES));
+
+    let thread_count = thread::available_parallelism().map(|n| n.get()).unwrap_or(1);
+    let found = AtomicBool::new(false);
+    let tries = AtomicU64::new(0);
+    let result: Mutex<Option<SigningKey>> = Mutex::new(None);
+
+    thread::scope(|scope| {
+        for _ in 0..thread_count {
+            let found = &found;
+            let tries = &tries;
+            let result = &result;
+            scope.spawn(move || {
+                let mut rng = OsRng;
+                while !found.load(Ordering::Relaxed) {
+                    if let Some(max) = max_tries {
+                        if tries.fetch_add(1, Ordering::Relaxed) >= max {
+                            return;
+                        }
+                    } else {
+                        tries.fetch_add(1, Ordering::Relaxed);
+                    }
+
+                    let signing_key = SigningKey::generate(&mut rng);
+                    let public_b64 = base64::engine::general_purpose::STANDARD
+                        .encode(signing_key.verifying_key().to_bytes());
+                    if public_b64.starts_with(prefix) && !found.swap(true, Ordering::SeqCst) {
+                        *result.lock().unwrap() = Some(signing_key);
+                        return;
+                    }
+                }
+            });
+        }
+    });
+
+    result
+        .into_inner()
+        .map_err(|_| "vanity key search mutex poisoned".to_string())?
+        .ok_or_else(|| {
+            // Each thread bumps `tries` once more when it sees the limit, so
+            // cap the reported count at the number of keys actually generated.
+            let attempted = tries.load(Ordering::Relaxed).min(max_tries.unwrap_or(u64::MAX));
+            format!("no key found with prefix \"{prefix}\" after {attempted} tries")
+        })
+}
+
 #[cfg(unix)]
 fn write_private_key_secure(path: &Path, content: &str) -> Result<(), String> {
     use std::os::unix::fs::OpenOptionsExt;
@@ -195,11 +489,11 @@ fn detect_gpu() -> Option<String> {
 
 fn cmd_start() -> Result<(), String> {
     let cfg = read_config_optional()?.ok_or_else(|| "config not found, run init".to_string())?;
-    let signing_key = load_private_key()?;
+    let identity = load_identity()?;
 
     let mut backoff = Duration::from_secs(1);
     loop {
-        match run_cycle(&cfg, &signing_key) {
+        match run_cycle(&cfg, identity.as_ref()) {
             Ok(()) => backoff = Duration::from_secs(1),
             Err(e) => {
                 error!(error = %e, "cycle failed");
@@ -211,11 +505,36 @@ fn cmd_start() -> Result<(), String> {
     }
 }
 
-fn run_cycle(cfg: &Config, signing_key: &SigningKey) -> Result<(), String> {
+fn run_cycle(cfg: &Config, identity: &dyn KeyBackend) -> Result<(), String> {
     heartbeat(cfg)?;
+    let nonce = fetch_nonce(cfg)?;
+
+    // Checked, but not yet recorded: recording happens only once
+    // `submit_signed_result` below has actually succeeded. This branch is
+    // reachable on restart after a crash that lands between `mark_nonce_used`
+    // and `clear_pending_attempt` below — the nonce is already marked used,
+    // but `pending_nonce` is still on disk, so the next cycle reuses it here
+    // and correctly recognizes it as already-submitted instead of resigning
+    // and resubmitting a second time.
+    if nonce_already_used(&nonce)? {
+        info!(nonce = %nonce, "nonce already used, skipping duplicate retry");
+        clear_pending_attempt()?;
+        return Ok(());
+    }
+
     let job = poll_job(cfg)?;
     let result = execute_dummy(&job)?;
-    submit_signed_result(cfg, signing_key, &result)?;
+    let counter = reserve_submission_counter()?;
+    submit_signed_result(cfg, identity, &result, &nonce, counter)?;
+
+    // Only committed after a successful submit, so a failed attempt leaves
+    // the nonce unrecorded and the counter reservation in place: the next
+    // retry reuses the exact same nonce/counter pair via `fetch_nonce` and
+    // `reserve_submission_counter` instead of losing the result or skipping
+    // ahead.
+    mark_nonce_used(&nonce)?;
+    commit_submission_counter(counter)?;
+    clear_pending_attempt()?;
     Ok(())
 }
 
@@ -227,6 +546,138 @@ fn heartbeat(cfg: &Config) -> Result<(), String> {
     Ok(())
 }
 
+/// Mints the single-use nonce for this submission attempt, or returns the
+/// one already pending from an earlier attempt of the same job so a retry
+/// during backoff reuses it instead of minting a fresh one every cycle (the
+/// ring buffer in `nonce_already_used` can only ever catch a duplicate
+/// retry if the nonce stays stable across it).
+///
+/// `heartbeat` and `poll_job` are dummy stand-ins with no real coordinator
+/// round-trip, so this nonce is generated locally with `OsRng` rather than
+/// fetched from a coordinator `newNonce`-style endpoint. A self-minted
+/// nonce only protects the worker against *its own* duplicate retries; it
+/// gives no anti-replay guarantee against a third party, since nothing
+/// stops a party who observes a submitted envelope from replaying its
+/// nonce, counter, and signature verbatim. Closing that hole requires a
+/// coordinator-issued nonce the worker cannot forge, at which point this
+/// function should fetch from that endpoint while preserving the
+/// reuse-across-retries behavior below.
+fn fetch_nonce(cfg: &Config) -> Result<String, String> {
+    if cfg.coordinator_url.trim().is_empty() {
+        return Err("invalid coordinator_url".to_string());
+    }
+
+    let path = pending_nonce_path()?;
+    if path.exists() {
+        let nonce = fs::read_to_string(&path)
+            .map_err(|e| format!("read pending_nonce failed: {e}"))?
+            .trim()
+            .to_string();
+        info!(worker = %cfg.name, nonce = %nonce, "reusing pending nonce for retry");
+        return Ok(nonce);
+    }
+
+    let mut raw = [0u8; 16];
+    OsRng.fill_bytes(&mut raw);
+    let nonce = hex_bytes(&raw);
+    fs::write(&path, &nonce).map_err(|e| format!("write pending_nonce failed: {e}"))?;
+    info!(worker = %cfg.name, nonce = %nonce, "nonce minted");
+    Ok(nonce)
+}
+
+/// Clears both halves of the pending-attempt state (nonce and reserved
+/// counter) once a cycle has either submitted successfully or been
+/// recognized as a duplicate retry, so the next cycle starts a fresh
+/// attempt for its own job.
+fn clear_pending_attempt() -> Result<(), String> {
+    for path in [pending_nonce_path()?, pending_counter_path()?] {
+        if path.exists() {
+            fs::remove_file(&path)
+                .map_err(|e| format!("remove {} failed: {e}", path.display()))?;
+        }
+    }
+    Ok(())
+}
+
+/// Reserves the counter value for this submission attempt, reusing the one
+/// already pending from an earlier try of the same job so a retry signs the
+/// same counter instead of advancing past it (advancing on a failed attempt
+/// would make the coordinator's strictly-increasing check reject every
+/// subsequent submission).
+fn reserve_submission_counter() -> Result<u64, String> {
+    let pending = pending_counter_path()?;
+    if pending.exists() {
+        return fs::read_to_string(&pending)
+            .map_err(|e| format!("read pending_counter failed: {e}"))?
+            .trim()
+            .parse()
+            .map_err(|e| format!("parse pending_counter failed: {e}"));
+    }
+
+    let counter_path = nonce_counter_path()?;
+    let current: u64 = if counter_path.exists() {
+        fs::read_to_string(&counter_path)
+            .map_err(|e| format!("read nonce_counter failed: {e}"))?
+            .trim()
+            .parse()
+            .map_err(|e| format!("parse nonce_counter failed: {e}"))?
+    } else {
+        0
+    };
+    let next = current + 1;
+    fs::write(&pending, next.to_string())
+        .map_err(|e| format!("write pending_counter failed: {e}"))?;
+    Ok(next)
+}
+
+/// Persists `counter` as the new submission counter baseline in
+/// `~/.openmesh`, so the coordinator can reject any future submission whose
+/// counter does not strictly increase. Called only after the submission
+/// carrying `counter` has succeeded.
+fn commit_submission_counter(counter: u64) -> Result<(), String> {
+    let path = nonce_counter_path()?;
+    fs::write(&path, counter.to_string()).map_err(|e| format!("write nonce_counter failed: {e}"))
+}
+
+/// Maximum number of recently used nonces kept on disk to detect the
+/// worker's own duplicate retries during backoff.
+const RECENT_NONCES_CAPACITY: usize = 64;
+
+/// Returns `true` if `nonce` is already present in the on-disk ring buffer
+/// of recently used nonces (a duplicate retry to skip), without recording
+/// it. Recording is a separate step (`mark_nonce_used`) performed only once
+/// the submission carrying `nonce` has actually succeeded.
+fn nonce_already_used(nonce: &str) -> Result<bool, String> {
+    let path = recent_nonces_path()?;
+    if !path.exists() {
+        return Ok(false);
+    }
+    let seen = fs::read_to_string(&path).map_err(|e| format!("read recent_nonces failed: {e}"))?;
+    Ok(seen.lines().any(|seen_nonce| seen_nonce == nonce))
+}
+
+/// Appends `nonce` to the on-disk ring buffer of recently used nonces,
+/// trimming it to `RECENT_NONCES_CAPACITY`.
+fn mark_nonce_used(nonce: &str) -> Result<(), String> {
+    let path = recent_nonces_path()?;
+    let mut seen: Vec<String> = if path.exists() {
+        fs::read_to_string(&path)
+            .map_err(|e| format!("read recent_nonces failed: {e}"))?
+            .lines()
+            .map(|s| s.to_string())
+            .collect()
+    } else {
+        Vec::new()
+    };
+
+    seen.push(nonce.to_string());
+    if seen.len() > RECENT_NONCES_CAPACITY {
+        let excess = seen.len() - RECENT_NONCES_CAPACITY;
+        seen.drain(0..excess);
+    }
+    fs::write(&path, seen.join("\n")).map_err(|e| format!("write recent_nonces failed: {e}"))
+}
+
 fn poll_job(cfg: &Config) -> Result<Value, String> {
     if cfg.api_key.trim().is_empty() {
         return Err("api_key is empty".to_string());
@@ -253,21 +704,125 @@ fn execute_dummy(job: &Value) -> Result<Value, String> {
     Ok(result)
 }
 
-fn submit_signed_result(cfg: &Config, signing_key: &SigningKey, result: &Value) -> Result<(), String> {
-    let canonical = canonical_json_string(result)?;
-    let digest = sha256_hex(canonical.as_bytes());
-    let signature = signing_key.sign(digest.as_bytes());
-    let signature_b64 = base64::engine::general_purpose::STANDARD.encode(signature.to_bytes());
+fn submit_signed_result(
+    cfg: &Config,
+    identity: &dyn KeyBackend,
+    result: &Value,
+    nonce: &str,
+    counter: u64,
+) -> Result<(), String> {
+    let mut payload = result.clone();
+    if let Value::Object(ref mut map) = payload {
+        map.insert("nonce".to_string(), Value::String(nonce.to_string()));
+        map.insert("counter".to_string(), Value::Number(counter.into()));
+    }
+
+    let canonical = canonical_json_string(&payload)?;
+    let envelope = build_jws_envelope(cfg, identity, canonical.as_bytes())?;
 
-    info!(
-        worker = %cfg.name,
-        digest = %digest,
-        signature = %signature_b64,
-        "signed result submitted"
-    );
+    info!(worker = %cfg.name, envelope = %envelope, "signed result submitted as JWS");
     Ok(())
 }
 
+/// Builds an RFC 7515 flattened JWS over `payload`: protected header and
+/// payload are base64url-encoded (unpadded, distinct from the STANDARD
+/// engine used for key/signature storage elsewhere), concatenated as
+/// `b64(protected).b64(payload)`, and that ASCII string is what gets signed.
+/// `alg` in the header is whatever `identity`'s backend negotiated, so a
+/// coordinator can select the matching verifier.
+fn build_jws_envelope(cfg: &Config, identity: &dyn KeyBackend, payload: &[u8]) -> Result<Value, String> {
+    let kid = identity.to_public_b64();
+    let url = format!("{}/submit", cfg.coordinator_url.trim_end_matches('/'));
+    let header = JwsProtectedHeader {
+        alg: identity.key_type().jws_alg(),
+        kid: &kid,
+        url: &url,
+    };
+    let protected_json =
+        serde_json::to_vec(&header).map_err(|e| format!("serialize jws header failed: {e}"))?;
+
+    let protected_b64 = base64::engine::general_purpose::URL_SAFE_NO_PAD.encode(protected_json);
+    let payload_b64 = base64::engine::general_purpose::URL_SAFE_NO_PAD.encode(payload);
+    let signing_input = format!("{protected_b64}.{payload_b64}");
+
+    let signature = identity.sign(signing_input.as_bytes());
+    let signature_b64 = base64::engine::general_purpose::URL_SAFE_NO_PAD.encode(signature);
+
+    Ok(serde_json::json!({
+        "protected": protected_b64,
+        "payload": payload_b64,
+        "signature": signature_b64,
+    }))
+}
+
+fn cmd_sign(file: &Path) -> Result<(), String> {
+    let identity = load_identity()?;
+    let digest = digest_json_file(file)?;
+    let signature = identity.sign(digest.as_bytes());
+    let signature_b64 = base64::engine::general_purpose::STANDARD.encode(signature);
+
+    if !identity.verify(digest.as_bytes(), &signature_b64)? {
+        return Err("freshly produced signature failed self-verification".to_string());
+    }
+
+    println!("signature={signature_b64}");
+    Ok(())
+}
+
+fn cmd_verify(file: &Path, signature: &str, public_key: &str, alg: KeyType) -> Result<(), String> {
+    let digest = digest_json_file(file)?;
+    let verified = match alg {
+        KeyType::Ed25519 => {
+            let verifying_key = decode_verifying_key(public_key)?;
+            verify_ed25519(&verifying_key, digest.as_bytes(), signature)?
+        }
+        KeyType::EcdsaP256 => {
+            let verifying_key = decode_p256_verifying_key(public_key)?;
+            verify_ecdsa_p256(&verifying_key, digest.as_bytes(), signature)?
+        }
+    };
+    if verified {
+        println!("OK");
+        Ok(())
+    } else {
+        println!("FAIL");
+        Err("signature verification failed".to_string())
+    }
+}
+
+fn cmd_recover(file: &Path, signature: &str) -> Result<(), String> {
+    let cfg = read_config_optional()?.ok_or_else(|| "config not found, run init".to_string())?;
+    let public_key = cfg
+        .public_key
+        .ok_or_else(|| "no public key in config, run keys first".to_string())?;
+    cmd_verify(file, signature, &public_key, cfg.key_type)
+}
+
+fn digest_json_file(file: &Path) -> Result<String, String> {
+    let raw = fs::read_to_string(file).map_err(|e| format!("read {} failed: {e}", file.display()))?;
+    let value: Value = serde_json::from_str(&raw).map_err(|e| format!("parse json failed: {e}"))?;
+    let canonical = canonical_json_string(&value)?;
+    Ok(sha256_hex(canonical.as_bytes()))
+}
+
+fn decode_verifying_key(public_key_b64: &str) -> Result<VerifyingKey, String> {
+    let bytes = base64::engine::general_purpose::STANDARD
+        .decode(public_key_b64)
+        .map_err(|e| format!("decode public_key failed: {e}"))?;
+    let arr: [u8; 32] = bytes
+        .as_slice()
+        .try_into()
+        .map_err(|_| "public key must be 32 bytes".to_string())?;
+    VerifyingKey::from_bytes(&arr).map_err(|e| format!("invalid public key: {e}"))
+}
+
+fn decode_p256_verifying_key(public_key_b64: &str) -> Result<P256VerifyingKey, String> {
+    let bytes = base64::engine::general_purpose::STANDARD
+        .decode(public_key_b64)
+        .map_err(|e| format!("decode public_key failed: {e}"))?;
+    P256VerifyingKey::from_sec1_bytes(&bytes).map_err(|e| format!("invalid public key: {e}"))
+}
+
 fn read_config_optional() -> Result<Option<Config>, String> {
     let path = config_path()?;
     if !path.exists() {
@@ -278,7 +833,10 @@ fn read_config_optional() -> Result<Option<Config>, String> {
     Ok(Some(cfg))
 }
 
-fn load_private_key() -> Result<SigningKey, String> {
+/// Loads the stored private key and wraps it in the `KeyBackend` matching
+/// the algorithm recorded in `Config` when the key was generated.
+fn load_identity() -> Result<Box<dyn KeyBackend>, String> {
+    let cfg = read_config_optional()?.ok_or_else(|| "config not found, run init".to_string())?;
     let raw = fs::read_to_string(key_path()?).map_err(|e| format!("read private_key failed: {e}"))?;
     let bytes = base64::engine::general_purpose::STANDARD
         .decode(raw.trim())
@@ -287,7 +845,15 @@ fn load_private_key() -> Result<SigningKey, String> {
         .as_slice()
         .try_into()
         .map_err(|_| "private key must be 32 bytes".to_string())?;
-    Ok(SigningKey::from_bytes(&arr))
+
+    match cfg.key_type {
+        KeyType::Ed25519 => Ok(Box::new(Ed25519Backend(SigningKey::from_bytes(&arr)))),
+        KeyType::EcdsaP256 => {
+            let signing_key = P256SigningKey::from_bytes((&arr).into())
+                .map_err(|e| format!("invalid p256 private key: {e}"))?;
+            Ok(Box::new(EcdsaP256Backend(signing_key)))
+        }
+    }
 }
 
 fn canonical_json_string(value: &Value) -> Result<String, String> {
@@ -314,10 +880,13 @@ fn canonicalize_value(value: &Value) -> Value {
 }
 
 fn sha256_hex(data: &[u8]) -> String {
+    hex_bytes(&sha256_bytes(data))
+}
+
+fn sha256_bytes(data: &[u8]) -> [u8; 32] {
     let mut hasher = Sha256::new();
     hasher.update(data);
-    let digest = hasher.finalize();
-    hex_bytes(&digest)
+    hasher.finalize().into()
 }
 
 fn hex_bytes(data: &[u8]) -> String {
@@ -330,8 +899,7 @@ fn hex_bytes(data: &[u8]) -> String {
     out
 }
 
-#[allow(dead_code)]
-fn verify_signature(verifying_key: &VerifyingKey, message: &[u8], sig_b64: &str) -> Result<bool, String> {
+fn verify_ed25519(verifying_key: &VerifyingKey, message: &[u8], sig_b64: &str) -> Result<bool, String> {
     let sig_bytes = base64::engine::general_purpose::STANDARD
         .decode(sig_b64)
         .map_err(|e| format!("decode signature failed: {e}"))?;
@@ -343,6 +911,17 @@ fn verify_signature(verifying_key: &VerifyingKey, message: &[u8], sig_b64: &str)
     Ok(verifying_key.verify(message, &signature).is_ok())
 }
 
+fn verify_ecdsa_p256(verifying_key: &P256VerifyingKey, message: &[u8], sig_b64: &str) -> Result<bool, String> {
+    use p256::ecdsa::signature::Verifier as _;
+
+    let sig_bytes = base64::engine::general_purpose::STANDARD
+        .decode(sig_b64)
+        .map_err(|e| format!("decode signature failed: {e}"))?;
+    let signature =
+        P256Signature::from_slice(&sig_bytes).map_err(|e| format!("invalid signature: {e}"))?;
+    Ok(verifying_key.verify(message, &signature).is_ok())
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -377,7 +956,102 @@ mod tests {
         let sig = sk.sign(msg);
         let sig_b64 = base64::engine::general_purpose::STANDARD.encode(sig.to_bytes());
 
-        let verified = verify_signature(&vk, msg, &sig_b64).expect("verification should run");
+        let verified = verify_ed25519(&vk, msg, &sig_b64).expect("verification should run");
         assert!(verified);
     }
+
+    /// A single Wycheproof-style test case: `msg_hex`/`sig_hex`/`public_key_hex`
+    /// are lowercase hex, `result` is `"valid"` or `"invalid"` (covering both
+    /// outright rejections and signature malleability).
+    #[derive(Debug, Deserialize)]
+    struct WycheproofVector {
+        msg_hex: String,
+        sig_hex: String,
+        public_key_hex: String,
+        result: String,
+    }
+
+    fn load_vectors(json: &str) -> Vec<WycheproofVector> {
+        serde_json::from_str(json).expect("embedded wycheproof vectors must parse")
+    }
+
+    fn decode_hex(s: &str) -> Vec<u8> {
+        (0..s.len())
+            .step_by(2)
+            .map(|i| u8::from_str_radix(&s[i..i + 2], 16).expect("vector hex must be well-formed"))
+            .collect()
+    }
+
+    /// Small embedded set covering a known-good signature plus the malformed
+    /// variants (wrong-length signatures, an all-zero key, a tampered
+    /// message, a non-canonical/malleable `S`) that `verify_ed25519` must
+    /// reject rather than silently accept.
+    const EMBEDDED_ED25519_VECTORS: &str = r#"[
+        {
+            "msg_hex": "6f70656e6d65736820777963686570726f6f662072656772657373696f6e20766563746f72",
+            "sig_hex": "a8679eaa81d20f7a6bb25b3026cc98ab9c94e554561e019e6efee4e5c3362e4ec7e1a8c118e96ebc6f347ea64d4578688607cdf81ce0d1bfd9223b8c04757300",
+            "public_key_hex": "79b5562e8fe654f94078b112e8a98ba7901f853ae695bed7e0e3910bad049664",
+            "result": "valid"
+        },
+        {
+            "msg_hex": "6e70656e6d65736820777963686570726f6f662072656772657373696f6e20766563746f72",
+            "sig_hex": "a8679eaa81d20f7a6bb25b3026cc98ab9c94e554561e019e6efee4e5c3362e4ec7e1a8c118e96ebc6f347ea64d4578688607cdf81ce0d1bfd9223b8c04757300",
+            "public_key_hex": "79b5562e8fe654f94078b112e8a98ba7901f853ae695bed7e0e3910bad049664",
+            "result": "invalid"
+        },
+        {
+            "msg_hex": "6f70656e6d65736820777963686570726f6f662072656772657373696f6e20766563746f72",
+            "sig_hex": "a8679eaa81d20f7a6bb25b3026cc98ab9c94e554561e019e6efee4e5c3362e4ec7e1a8c118e96ebc6f347ea64d4578688607cdf81ce0d1bfd9223b8c047573",
+            "public_key_hex": "79b5562e8fe654f94078b112e8a98ba7901f853ae695bed7e0e3910bad049664",
+            "result": "invalid"
+        },
+        {
+            "msg_hex": "6f70656e6d65736820777963686570726f6f662072656772657373696f6e20766563746f72",
+            "sig_hex": "a8679eaa81d20f7a6bb25b3026cc98ab9c94e554561e019e6efee4e5c3362e4ec7e1a8c118e96ebc6f347ea64d4578688607cdf81ce0d1bfd9223b8c0475730000",
+            "public_key_hex": "79b5562e8fe654f94078b112e8a98ba7901f853ae695bed7e0e3910bad049664",
+            "result": "invalid"
+        },
+        {
+            "msg_hex": "6f70656e6d65736820777963686570726f6f662072656772657373696f6e20766563746f72",
+            "sig_hex": "a8679eaa81d20f7a6bb25b3026cc98ab9c94e554561e019e6efee4e5c3362e4ec7e1a8c118e96ebc6f347ea64d4578688607cdf81ce0d1bfd9223b8c04757301",
+            "public_key_hex": "79b5562e8fe654f94078b112e8a98ba7901f853ae695bed7e0e3910bad049664",
+            "result": "invalid"
+        },
+        {
+            "msg_hex": "6f70656e6d65736820777963686570726f6f662072656772657373696f6e20766563746f72",
+            "sig_hex": "a8679eaa81d20f7a6bb25b3026cc98ab9c94e554561e019e6efee4e5c3362e4ec7e1a8c118e96ebc6f347ea64d4578688607cdf81ce0d1bfd9223b8c04757300",
+            "public_key_hex": "0000000000000000000000000000000000000000000000000000000000000000",
+            "result": "invalid"
+        },
+        {
+            "msg_hex": "6f70656e6d65736820777963686570726f6f662072656772657373696f6e20766563746f72",
+            "sig_hex": "a8679eaa81d20f7a6bb25b3026cc98ab9c94e554561e019e6efee4e5c3362e4eb4b59e1e334c811446d175492c3f577d8607cdf81ce0d1bfd9223b8c04757310",
+            "public_key_hex": "79b5562e8fe654f94078b112e8a98ba7901f853ae695bed7e0e3910bad049664",
+            "result": "invalid"
+        }
+    ]"#;
+
+    #[test]
+    fn wycheproof_style_ed25519_vectors() {
+        for vector in load_vectors(EMBEDDED_ED25519_VECTORS) {
+            let message = decode_hex(&vector.msg_hex);
+            let sig_bytes = decode_hex(&vector.sig_hex);
+            let sig_b64 = base64::engine::general_purpose::STANDARD.encode(&sig_bytes);
+            let pub_bytes = decode_hex(&vector.public_key_hex);
+
+            let accepted = pub_bytes
+                .as_slice()
+                .try_into()
+                .ok()
+                .and_then(|arr: [u8; 32]| VerifyingKey::from_bytes(&arr).ok())
+                .and_then(|vk| verify_ed25519(&vk, &message, &sig_b64).ok())
+                .unwrap_or(false);
+
+            match vector.result.as_str() {
+                "valid" => assert!(accepted, "expected vector to verify: {vector:?}"),
+                "invalid" => assert!(!accepted, "expected vector to be rejected: {vector:?}"),
+                other => panic!("unknown wycheproof result {other}"),
+            }
+        }
+    }
 }